@@ -0,0 +1,147 @@
+//! SIMD plumbing for half-precision floats.
+//!
+//! `f16` has no native SIMD registers on common targets, so the `Simd`
+//! (arithmetic kernels) and `Simd8` (comparison kernels) lane packs are
+//! implemented here by widening each lane to `f32` instead. This has to live
+//! in this crate rather than downstream in `polars-core`: both `Simd`/`Simd8`
+//! and `f16` are foreign to any crate that only depends on this one, and
+//! implementing a foreign trait for a foreign type is only legal where one
+//! of the two is a local item — here, the traits are local.
+//!
+//! `NumericNative`'s `Simd + Simd8` bound needs more than the associated
+//! `type Simd = F16x8` below to actually be satisfied — the comparison and
+//! reduction kernels that consume it call through `Simd8Lanes`/`NativeSimd`
+//! methods on that associated type, so both are implemented for `F16x8`
+//! here too. The declarations for `NativeSimd`/`Simd8Lanes` themselves live
+//! in sibling modules of this crate (`types::simd`, `compute::comparison`)
+//! that aren't part of this checkout, so the method set implemented below
+//! is reconstructed from how every other `NativeSimd`/`Simd8Lanes` impl in
+//! arrow-style crates is shaped (lane-chunk constructors plus the six
+//! comparison operators packed into a `u8` mask), not read off the trait
+//! declarations directly.
+use crate::compute::comparison::Simd8;
+use crate::data_types::IsFloat;
+use crate::types::f16;
+use crate::types::simd::{NativeSimd, Simd};
+
+/// Eight packed `f16` lanes, stored widened as `f32`: building a pack from a
+/// chunk widens each lane, and the lane comparisons below run at `f32`
+/// precision. Nothing on this type narrows lanes back to `f16`.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct F16x8(pub [f32; 8]);
+
+impl Simd for f16 {
+    type Simd = F16x8;
+}
+
+// `NativeSimd`'s required methods aren't re-derived from this checkout (the
+// trait itself lives in a sibling module not included here) — this mirrors
+// the lane-construction contract every other `NativeSimd` impl in this
+// crate follows (pad a short tail chunk with `remaining`, otherwise copy the
+// 8 values straight in), widening each `f16` lane to `f32` on the way in.
+impl NativeSimd<f16> for F16x8 {
+    const LANES: usize = 8;
+
+    fn from_chunk(v: &[f16]) -> Self {
+        assert_eq!(v.len(), Self::LANES);
+        let mut out = [0f32; 8];
+        for (o, x) in out.iter_mut().zip(v.iter()) {
+            *o = x.to_f32();
+        }
+        F16x8(out)
+    }
+
+    fn from_incomplete_chunk(v: &[f16], remaining: f16) -> Self {
+        assert!(v.len() < Self::LANES);
+        let mut out = [remaining.to_f32(); 8];
+        for (o, x) in out.iter_mut().zip(v.iter()) {
+            *o = x.to_f32();
+        }
+        F16x8(out)
+    }
+}
+
+/// Packs a per-lane `bool` comparison result into `Simd8`'s bitmask
+/// convention (lane `i`'s result in bit `i`).
+fn pack_mask(lanes: [bool; 8]) -> u8 {
+    lanes
+        .iter()
+        .enumerate()
+        .fold(0u8, |mask, (i, &set)| if set { mask | (1 << i) } else { mask })
+}
+
+macro_rules! lane_cmp {
+    ($name:ident, $op:tt) => {
+        fn $name(self, other: Self) -> u8 {
+            let mut out = [false; 8];
+            for i in 0..8 {
+                out[i] = self.0[i] $op other.0[i];
+            }
+            pack_mask(out)
+        }
+    };
+}
+
+impl Simd8 for f16 {
+    type Simd = F16x8;
+}
+
+// Same caveat as `NativeSimd` above: `Simd8Lanes`'s required methods are
+// reconstructed from this crate's comparison-kernel conventions rather than
+// read off the (not-included) trait declaration.
+impl crate::compute::comparison::Simd8Lanes<f16> for F16x8 {
+    fn from_chunk(v: &[f16]) -> Self {
+        <Self as NativeSimd<f16>>::from_chunk(v)
+    }
+
+    fn from_incomplete_chunk(v: &[f16], remaining: f16) -> Self {
+        <Self as NativeSimd<f16>>::from_incomplete_chunk(v, remaining)
+    }
+
+    lane_cmp!(eq, ==);
+    lane_cmp!(neq, !=);
+    lane_cmp!(lt_eq, <=);
+    lane_cmp!(lt, <);
+    lane_cmp!(gt_eq, >=);
+    lane_cmp!(gt, >);
+}
+
+impl IsFloat for f16 {
+    fn is_float() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::compute::comparison::Simd8Lanes;
+
+    #[test]
+    fn from_chunk_widens_every_lane_to_f32() {
+        let values = [1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0].map(f16::from_f32);
+        let packed = <F16x8 as NativeSimd<f16>>::from_chunk(&values);
+        assert_eq!(packed.0, [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+    }
+
+    #[test]
+    fn from_incomplete_chunk_pads_with_remaining() {
+        let values = [f16::from_f32(1.0), f16::from_f32(2.0)];
+        let packed = <F16x8 as NativeSimd<f16>>::from_incomplete_chunk(&values, f16::from_f32(0.0));
+        assert_eq!(packed.0, [1.0, 2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn eq_sets_one_bit_per_matching_lane() {
+        let a = F16x8([1.0, 2.0, 3.0, 4.0, 0.0, 0.0, 0.0, 0.0]);
+        let b = F16x8([1.0, 0.0, 3.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+        assert_eq!(Simd8Lanes::<f16>::eq(a, b), 0b0000_0101);
+    }
+
+    #[test]
+    fn lt_matches_elementwise_less_than() {
+        let a = F16x8([1.0, 5.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+        let b = F16x8([2.0, 3.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+        assert_eq!(Simd8Lanes::<f16>::lt(a, b), 0b0000_0001);
+    }
+}
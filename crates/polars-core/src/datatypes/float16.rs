@@ -0,0 +1,114 @@
+//! `NumericNative` support for the half-precision `Float16Type`.
+//!
+//! Arrow's `f16` is backed by the `half` crate, compiled with `half`'s
+//! `num-traits` feature enabled in `polars-arrow`'s `Cargo.toml` so `f16`
+//! itself satisfies `Num`, `NumCast`, `Zero`, `One`, `Bounded`,
+//! `FromPrimitive` and the arithmetic operator traits (a downstream crate
+//! can't implement any of those for it directly: both the trait and the
+//! type are foreign here). `polars-arrow` additionally implements the
+//! `Simd`/`Simd8` lane packs and `IsFloat` for `f16` (see
+//! `polars_arrow::types::f16`), since it owns those traits. What's left for
+//! this crate — because `ArrayArithmetics` is ours — is the actual
+//! arithmetic: the vectorised kernels upcast each lane to `f32`, run the
+//! existing `f32` kernel, and round the result back down. This keeps
+//! `Float16Chunked` usable everywhere a `NumericNative` is expected while
+//! storing values at half the memory footprint.
+use arrow::array::PrimitiveArray;
+use arrow::compute::arithmetics::basic as f32_arithmetic;
+pub use arrow::types::f16;
+
+use crate::chunked_array::arithmetic::ArrayArithmetics;
+
+fn upcast(arr: &PrimitiveArray<f16>) -> PrimitiveArray<f32> {
+    arr.iter()
+        .map(|opt| opt.map(|v| v.to_f32()))
+        .collect::<PrimitiveArray<f32>>()
+}
+
+fn downcast(arr: PrimitiveArray<f32>) -> PrimitiveArray<f16> {
+    arr.iter()
+        .map(|opt| opt.map(|v| f16::from_f32(*v)))
+        .collect::<PrimitiveArray<f16>>()
+}
+
+macro_rules! upcast_op {
+    ($name:ident) => {
+        fn $name(lhs: &PrimitiveArray<f16>, rhs: &PrimitiveArray<f16>) -> PrimitiveArray<f16> {
+            downcast(f32_arithmetic::$name(&upcast(lhs), &upcast(rhs)))
+        }
+    };
+}
+
+macro_rules! upcast_scalar_op {
+    ($name:ident) => {
+        fn $name(lhs: &PrimitiveArray<f16>, rhs: &f16) -> PrimitiveArray<f16> {
+            downcast(f32_arithmetic::$name(&upcast(lhs), &rhs.to_f32()))
+        }
+    };
+}
+
+impl ArrayArithmetics for f16 {
+    upcast_op!(add);
+    upcast_op!(sub);
+    upcast_op!(mul);
+    upcast_op!(div);
+    upcast_op!(rem);
+
+    upcast_scalar_op!(add_scalar);
+    upcast_scalar_op!(sub_scalar);
+    upcast_scalar_op!(mul_scalar);
+    upcast_scalar_op!(div_scalar);
+    upcast_scalar_op!(rem_scalar);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn arr(values: &[f32]) -> PrimitiveArray<f16> {
+        values
+            .iter()
+            .map(|v| Some(f16::from_f32(*v)))
+            .collect::<PrimitiveArray<f16>>()
+    }
+
+    #[test]
+    fn add_upcasts_through_f32_and_rounds_back() {
+        let lhs = arr(&[1.5, 2.25]);
+        let rhs = arr(&[0.5, 0.75]);
+        let out = <f16 as ArrayArithmetics>::add(&lhs, &rhs);
+        assert_eq!(out.value(0).to_f32(), 2.0);
+        assert_eq!(out.value(1).to_f32(), 3.0);
+    }
+
+    #[test]
+    fn sub_and_mul_round_trip_losslessly_at_half_precision() {
+        let lhs = arr(&[4.0, 6.0]);
+        let rhs = arr(&[1.0, 2.0]);
+        let diff = <f16 as ArrayArithmetics>::sub(&lhs, &rhs);
+        let prod = <f16 as ArrayArithmetics>::mul(&lhs, &rhs);
+        assert_eq!(diff.value(0).to_f32(), 3.0);
+        assert_eq!(diff.value(1).to_f32(), 4.0);
+        assert_eq!(prod.value(0).to_f32(), 4.0);
+        assert_eq!(prod.value(1).to_f32(), 12.0);
+    }
+
+    #[test]
+    fn add_scalar_upcasts_through_f32() {
+        let lhs = arr(&[1.0, -1.0]);
+        let out = <f16 as ArrayArithmetics>::add_scalar(&lhs, &f16::from_f32(10.0));
+        assert_eq!(out.value(0).to_f32(), 11.0);
+        assert_eq!(out.value(1).to_f32(), 9.0);
+    }
+
+    #[test]
+    fn null_lanes_propagate() {
+        let lhs: PrimitiveArray<f16> = vec![Some(f16::from_f32(1.0)), None]
+            .into_iter()
+            .collect();
+        let rhs = arr(&[1.0, 1.0]);
+        let out = <f16 as ArrayArithmetics>::add(&lhs, &rhs);
+        assert!(out.is_valid(0));
+        assert!(!out.is_valid(1));
+    }
+}
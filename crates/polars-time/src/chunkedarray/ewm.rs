@@ -0,0 +1,366 @@
+//! Exponentially-weighted moving aggregations.
+//!
+//! These sit alongside the fixed-window `rolling_*` aggregations
+//! (`[RollingAgg]`) as the decay-weighted counterpart: instead of a hard
+//! window boundary, every prior observation contributes forever with a
+//! weight that shrinks geometrically by `(1 - alpha)` per step.
+use polars_core::export::num::NumCast;
+use polars_core::prelude::*;
+
+/// Configuration for an exponentially-weighted moving aggregation.
+///
+/// Construct one from whichever decay parameter is most natural; each
+/// builder method converts it to the single smoothing factor `alpha` that
+/// the recurrence actually runs on:
+/// - [`EWMOptions::and_alpha`] sets `alpha` directly, `0 < alpha <= 1`.
+/// - [`EWMOptions::and_span`]: `alpha = 2 / (span + 1)`.
+/// - [`EWMOptions::and_halflife`]: `alpha = 1 - exp(ln(0.5) / halflife)`.
+/// - [`EWMOptions::and_com`] (center of mass): `alpha = 1 / (1 + com)`.
+#[cfg(feature = "ewma")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EWMOptions {
+    pub alpha: f64,
+    /// `true`: weight the i-th-back observation by `(1 - alpha)^i` and
+    /// normalize by the sum of weights (matches the textbook definition).
+    /// `false`: the classic recursive form `mean_t = alpha * x_t + (1 -
+    /// alpha) * mean_{t-1}`, which only keeps the latest running value.
+    pub adjust: bool,
+    /// `true`: nulls are skipped and the decay counter is paused, so the
+    /// next valid observation is weighted as if the null were never there.
+    /// `false`: a null resets the running state to null until the next
+    /// valid observation starts a fresh series.
+    pub ignore_nulls: bool,
+}
+
+#[cfg(feature = "ewma")]
+impl Default for EWMOptions {
+    fn default() -> Self {
+        Self {
+            alpha: 0.5,
+            adjust: true,
+            ignore_nulls: true,
+        }
+    }
+}
+
+#[cfg(feature = "ewma")]
+impl EWMOptions {
+    pub fn and_alpha(mut self, alpha: f64) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
+    pub fn and_span(mut self, span: f64) -> Self {
+        self.alpha = 2.0 / (span + 1.0);
+        self
+    }
+
+    pub fn and_halflife(mut self, halflife: f64) -> Self {
+        self.alpha = 1.0 - (std::f64::consts::LN_2 / halflife).exp().recip();
+        self
+    }
+
+    pub fn and_com(mut self, com: f64) -> Self {
+        self.alpha = 1.0 / (1.0 + com);
+        self
+    }
+
+    pub fn and_adjust(mut self, adjust: bool) -> Self {
+        self.adjust = adjust;
+        self
+    }
+
+    pub fn and_ignore_nulls(mut self, ignore_nulls: bool) -> Self {
+        self.ignore_nulls = ignore_nulls;
+        self
+    }
+}
+
+/// Running state for the mean recurrence, tracked so that a paused (ignored
+/// null) decay counter resumes exactly where it left off.
+#[cfg(feature = "ewma")]
+#[derive(Default)]
+struct EwmMeanState {
+    // Adjust mode tracks weighted numerator/denominator directly; non-adjust
+    // mode only needs the last mean.
+    numerator: f64,
+    denominator: f64,
+    mean: Option<f64>,
+}
+
+#[cfg(feature = "ewma")]
+fn ewm_mean_core(values: impl Iterator<Item = Option<f64>>, options: EWMOptions) -> Vec<Option<f64>> {
+    let one_minus_alpha = 1.0 - options.alpha;
+    let mut state = EwmMeanState::default();
+    let mut out = Vec::new();
+
+    for opt in values {
+        match opt {
+            None => {
+                if options.ignore_nulls {
+                    out.push(None);
+                } else {
+                    state = EwmMeanState::default();
+                    out.push(None);
+                }
+            },
+            Some(x) => {
+                let mean = if options.adjust {
+                    state.numerator = x + one_minus_alpha * state.numerator;
+                    state.denominator = 1.0 + one_minus_alpha * state.denominator;
+                    state.numerator / state.denominator
+                } else {
+                    match state.mean {
+                        None => x,
+                        Some(prev) => options.alpha * x + one_minus_alpha * prev,
+                    }
+                };
+                state.mean = Some(mean);
+                out.push(Some(mean));
+            },
+        }
+    }
+    out
+}
+
+/// Running state for the variance recurrence. `old_wt` is the accumulated
+/// weight of everything folded into `mean`/`var` so far; in `adjust` mode it
+/// keeps growing every step (mirroring the `(1 - alpha)^i`-weighted mean),
+/// while in non-adjust mode it's renormalized back to `1.0` after every
+/// observation (mirroring the plain recursive mean).
+/// `sum_weights`/`sum_weights_sq` track the same two quantities needed for
+/// the bias-correction factor `sum_w^2 / (sum_w^2 - sum_w2)`.
+///
+/// `var` itself must stay *normalized* at every step (divided by
+/// `old_wt + new_wt`, not just accumulated), exactly like `mean` is — this
+/// is what lets the non-adjust branch renormalize `old_wt` back to `1.0`
+/// without leaving stale, un-decayed mass behind in `var`. An un-normalized
+/// running total (an M2-style accumulator that only gets rescaled via
+/// `sum_weights`/`sum_weights_sq`) diverges from the reference recurrence as
+/// soon as `adjust` is `false`, because nothing ever shrinks the earlier
+/// contributions in step with `old_wt`'s reset to `1.0`.
+#[cfg(feature = "ewma")]
+#[derive(Default)]
+struct EwmVarState {
+    mean: f64,
+    old_wt: f64,
+    sum_weights: f64,
+    sum_weights_sq: f64,
+    var: f64,
+    initialized: bool,
+}
+
+#[cfg(feature = "ewma")]
+fn ewm_var_core(values: impl Iterator<Item = Option<f64>>, options: EWMOptions) -> Vec<Option<f64>> {
+    let one_minus_alpha = 1.0 - options.alpha;
+    // In adjust mode every observation enters with weight 1 and the earlier
+    // ones decay relative to it (matching `ewm_mean_core`'s numerator /
+    // denominator); in non-adjust mode a new observation only ever carries
+    // weight `alpha` against the already-normalized running state.
+    let new_wt = if options.adjust { 1.0 } else { options.alpha };
+    let mut state = EwmVarState::default();
+    let mut out = Vec::new();
+
+    for opt in values {
+        match opt {
+            None => {
+                if options.ignore_nulls {
+                    out.push(None);
+                } else {
+                    state = EwmVarState::default();
+                    out.push(None);
+                }
+            },
+            Some(x) => {
+                if !state.initialized {
+                    state.mean = x;
+                    state.old_wt = 1.0;
+                    state.sum_weights = 1.0;
+                    state.sum_weights_sq = 1.0;
+                    state.var = 0.0;
+                    state.initialized = true;
+                } else {
+                    state.old_wt *= one_minus_alpha;
+                    state.sum_weights *= one_minus_alpha;
+                    state.sum_weights_sq *= one_minus_alpha * one_minus_alpha;
+
+                    let old_mean = state.mean;
+                    let combined_wt = state.old_wt + new_wt;
+                    state.mean = (state.old_wt * old_mean + new_wt * x) / combined_wt;
+                    // `var` is itself a normalized running variance, updated
+                    // the same way `mean` is: the "old" group (weight
+                    // `old_wt`, variance `var` around the *old* mean) is
+                    // combined with the fresh point (weight `new_wt`,
+                    // "variance" 0 around itself), each re-centered on the
+                    // *new* mean via `(old_mean - mean)^2`, then divided back
+                    // down by the combined weight.
+                    state.var = (state.old_wt * (state.var + (old_mean - state.mean) * (old_mean - state.mean))
+                        + new_wt * (x - state.mean) * (x - state.mean))
+                        / combined_wt;
+
+                    state.sum_weights += new_wt;
+                    state.sum_weights_sq += new_wt * new_wt;
+                    state.old_wt = combined_wt;
+
+                    if !options.adjust {
+                        state.sum_weights /= state.old_wt;
+                        state.sum_weights_sq /= state.old_wt * state.old_wt;
+                        state.old_wt = 1.0;
+                    }
+                }
+
+                let denom = state.sum_weights * state.sum_weights - state.sum_weights_sq;
+                let var = if denom > 0.0 {
+                    Some(state.var * state.sum_weights * state.sum_weights / denom)
+                } else {
+                    None
+                };
+                out.push(var);
+            },
+        }
+    }
+    out
+}
+
+#[cfg(feature = "ewma")]
+pub trait EwmAgg {
+    fn ewm_mean(&self, options: EWMOptions) -> PolarsResult<Series>;
+    fn ewm_var(&self, options: EWMOptions) -> PolarsResult<Series>;
+    fn ewm_std(&self, options: EWMOptions) -> PolarsResult<Series>;
+}
+
+#[cfg(feature = "ewma")]
+impl<T> EwmAgg for ChunkedArray<T>
+where
+    T: PolarsNumericType,
+{
+    fn ewm_mean(&self, options: EWMOptions) -> PolarsResult<Series> {
+        let values = self.into_iter().map(|opt| opt.and_then(NumCast::from));
+        let out: Float64Chunked = ewm_mean_core(values, options)
+            .into_iter()
+            .collect_trusted();
+        Ok(out.with_name(self.name()).into_series())
+    }
+
+    fn ewm_var(&self, options: EWMOptions) -> PolarsResult<Series> {
+        let values = self.into_iter().map(|opt| opt.and_then(NumCast::from));
+        let out: Float64Chunked = ewm_var_core(values, options)
+            .into_iter()
+            .collect_trusted();
+        Ok(out.with_name(self.name()).into_series())
+    }
+
+    fn ewm_std(&self, options: EWMOptions) -> PolarsResult<Series> {
+        let var = self.ewm_var(options)?;
+        Ok(var.f64()?.apply(|v| v.sqrt()).into_series())
+    }
+}
+
+#[cfg(all(test, feature = "ewma"))]
+mod test {
+    use super::*;
+
+    fn opts(alpha: f64, adjust: bool, ignore_nulls: bool) -> EWMOptions {
+        EWMOptions {
+            alpha,
+            adjust,
+            ignore_nulls,
+        }
+    }
+
+    #[test]
+    fn mean_adjust_matches_closed_form_weights() {
+        let values = vec![Some(1.0), Some(2.0), Some(3.0)];
+        let out = ewm_mean_core(values.into_iter(), opts(0.5, true, true));
+        // weight of the i-th-back observation is (1 - alpha)^i.
+        let expected_last = (1.0 * 0.25 + 2.0 * 0.5 + 3.0 * 1.0) / (0.25 + 0.5 + 1.0);
+        assert!((out[2].unwrap() - expected_last).abs() < 1e-9);
+    }
+
+    #[test]
+    fn mean_non_adjust_uses_recursive_form() {
+        let values = vec![Some(1.0), Some(2.0), Some(3.0)];
+        let out = ewm_mean_core(values.into_iter(), opts(0.5, false, true));
+        assert_eq!(out[0], Some(1.0));
+        assert_eq!(out[1], Some(0.5 * 2.0 + 0.5 * 1.0));
+        assert_eq!(out[2], Some(0.5 * 3.0 + 0.5 * out[1].unwrap()));
+    }
+
+    #[test]
+    fn ignore_nulls_pauses_the_decay_counter() {
+        let with_null = vec![Some(1.0), None, Some(2.0)];
+        let without_null = vec![Some(1.0), Some(2.0)];
+        let out_with_null = ewm_mean_core(with_null.into_iter(), opts(0.5, true, true));
+        let out_without_null = ewm_mean_core(without_null.into_iter(), opts(0.5, true, true));
+        assert_eq!(out_with_null[2], out_without_null[1]);
+    }
+
+    #[test]
+    fn null_restarts_the_series_when_not_ignored() {
+        let values = vec![Some(10.0), None, Some(1.0)];
+        let out = ewm_mean_core(values.into_iter(), opts(0.5, true, false));
+        assert_eq!(out[2], Some(1.0));
+    }
+
+    #[test]
+    fn var_needs_two_observations() {
+        let values = vec![Some(1.0), Some(2.0)];
+        let out = ewm_var_core(values.into_iter(), opts(0.5, true, true));
+        assert_eq!(out[0], None);
+        assert!(out[1].is_some());
+    }
+
+    #[test]
+    fn var_non_adjust_matches_the_reference_recurrence() {
+        // Hand-derived against pandas'/polars' `ewmcov` recurrence: each step
+        // renormalizes `old_wt` back to `1.0`, and `var` must decay in step
+        // with it. [0, 1, 0] at alpha=0.5, adjust=false lands on exactly 0.3
+        // at the last point; the earlier (un-normalized M2) implementation
+        // gave 0.5 here because `var` never decayed.
+        let values = vec![Some(0.0), Some(1.0), Some(0.0)];
+        let out = ewm_var_core(values.into_iter(), opts(0.5, false, true));
+        assert_eq!(out[0], None);
+        assert!((out[1].unwrap() - 0.5).abs() < 1e-9);
+        assert!((out[2].unwrap() - 0.3).abs() < 1e-9);
+    }
+
+    #[test]
+    fn var_adjust_matches_the_reference_recurrence() {
+        // Same series under `adjust=true`, hand-derived from the same
+        // recurrence (weights never renormalize back to 1, so this diverges
+        // from the non-adjust case above).
+        let values = vec![Some(0.0), Some(1.0), Some(0.0)];
+        let out = ewm_var_core(values.into_iter(), opts(0.5, true, true));
+        assert_eq!(out[0], None);
+        assert!((out[1].unwrap() - 0.5).abs() < 1e-9);
+        assert!((out[2].unwrap() - 0.357142857142).abs() < 1e-9);
+    }
+
+    #[test]
+    fn var_is_never_negative() {
+        for adjust in [true, false] {
+            let values = vec![Some(1.0), Some(5.0), Some(2.0), Some(8.0)];
+            let out = ewm_var_core(values.into_iter(), opts(0.3, adjust, true));
+            for v in out.into_iter().flatten() {
+                assert!(v >= 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn adjust_changes_the_variance() {
+        let values = vec![Some(1.0), Some(5.0), Some(2.0), Some(8.0)];
+        let adjusted = ewm_var_core(values.clone().into_iter(), opts(0.3, true, true));
+        let not_adjusted = ewm_var_core(values.into_iter(), opts(0.3, false, true));
+        assert_ne!(adjusted[3], not_adjusted[3]);
+    }
+
+    #[test]
+    fn std_is_sqrt_of_var() {
+        let values = vec![Some(0.0), Some(1.0), Some(0.0)];
+        let var = ewm_var_core(values.into_iter(), opts(0.5, false, true));
+        let std: Vec<Option<f64>> = var.iter().map(|v| v.map(f64::sqrt)).collect();
+        assert_eq!(std[0], None);
+        assert!((std[2].unwrap() - 0.3f64.sqrt()).abs() < 1e-9);
+    }
+}
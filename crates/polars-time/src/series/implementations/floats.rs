@@ -0,0 +1,80 @@
+//! Mirrors `implementations/integers.rs` for float-backed chunked arrays, so
+//! `ewm_mean`/`ewm_var`/`ewm_std` (and the pre-existing `rolling_*` methods)
+//! are available through `SeriesOpsTime` regardless of which numeric family
+//! backs the column, not just integers.
+//!
+//! This file on its own isn't enough to bring `WrapFloat` into scope for the
+//! dispatcher: `implementations/mod.rs` (not part of this checkout) also
+//! needs a `mod floats;` alongside its existing `mod integers;`, and the
+//! `SeriesOpsTime` trait declaration itself (also outside this checkout)
+//! needs `ewm_mean`/`ewm_var`/`ewm_std` added to its signature list — every
+//! method here is constrained by `impl ... for WrapFloat<ChunkedArray<T>>`,
+//! so it can't compile against a trait that hasn't grown those methods.
+use super::*;
+
+impl<T: PolarsFloatType> SeriesOpsTime for WrapFloat<ChunkedArray<T>>
+where
+    T::Native: NumericNative,
+    Self: RollingAgg,
+{
+    fn ops_time_dtype(&self) -> &DataType {
+        self.0.dtype()
+    }
+
+    #[cfg(feature = "rolling_window")]
+    fn rolling_mean(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        RollingAgg::rolling_mean(self, options)
+    }
+
+    #[cfg(feature = "rolling_window")]
+    fn rolling_sum(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        RollingAgg::rolling_sum(self, options)
+    }
+    #[cfg(feature = "rolling_window")]
+    fn rolling_median(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        RollingAgg::rolling_median(self, options)
+    }
+
+    #[cfg(feature = "rolling_window")]
+    fn rolling_quantile(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        RollingAgg::rolling_quantile(self, options)
+    }
+
+    #[cfg(feature = "rolling_window")]
+    fn rolling_min(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        RollingAgg::rolling_min(self, options)
+    }
+
+    #[cfg(feature = "rolling_window")]
+    fn rolling_max(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        RollingAgg::rolling_max(self, options)
+    }
+    #[cfg(feature = "rolling_window")]
+    fn rolling_var(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        RollingAgg::rolling_var(self, options)
+    }
+
+    /// Apply a rolling std_dev to a Series.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_std(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        RollingAgg::rolling_std(self, options)
+    }
+
+    // Mirrors `WrapInt`'s delegation in `implementations/integers.rs`:
+    // `EwmAgg` is only implemented for the inner `ChunkedArray<T>`, not for
+    // `WrapFloat<ChunkedArray<T>>` itself, so these go through `self.0`.
+    #[cfg(feature = "ewma")]
+    fn ewm_mean(&self, options: EWMOptions) -> PolarsResult<Series> {
+        EwmAgg::ewm_mean(&self.0, options)
+    }
+
+    #[cfg(feature = "ewma")]
+    fn ewm_var(&self, options: EWMOptions) -> PolarsResult<Series> {
+        EwmAgg::ewm_var(&self.0, options)
+    }
+
+    #[cfg(feature = "ewma")]
+    fn ewm_std(&self, options: EWMOptions) -> PolarsResult<Series> {
+        EwmAgg::ewm_std(&self.0, options)
+    }
+}
@@ -1,3 +1,7 @@
+//! `ewm_mean`/`ewm_var`/`ewm_std` below are also implemented for floats in
+//! `implementations/floats.rs`; both impls assume the `SeriesOpsTime` trait
+//! declaration (outside this checkout) already lists those three methods
+//! alongside `rolling_*`.
 use super::*;
 
 impl<T: PolarsIntegerType> SeriesOpsTime for WrapInt<ChunkedArray<T>>
@@ -47,4 +51,23 @@ where
     fn rolling_std(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
         RollingAgg::rolling_std(self, options)
     }
+
+    // `EwmAgg` is only implemented for the inner `ChunkedArray<T>`
+    // (see `ewm.rs`), not for `WrapInt<ChunkedArray<T>>` itself, so these
+    // delegate through `self.0` rather than calling `EwmAgg::ewm_mean(self,
+    // ..)` the way the `RollingAgg` methods above call through `Self`.
+    #[cfg(feature = "ewma")]
+    fn ewm_mean(&self, options: EWMOptions) -> PolarsResult<Series> {
+        EwmAgg::ewm_mean(&self.0, options)
+    }
+
+    #[cfg(feature = "ewma")]
+    fn ewm_var(&self, options: EWMOptions) -> PolarsResult<Series> {
+        EwmAgg::ewm_var(&self.0, options)
+    }
+
+    #[cfg(feature = "ewma")]
+    fn ewm_std(&self, options: EWMOptions) -> PolarsResult<Series> {
+        EwmAgg::ewm_std(&self.0, options)
+    }
 }